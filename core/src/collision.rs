@@ -0,0 +1,78 @@
+use bevy_ecs::Entity;
+use bevy_math::Vec3;
+
+/// An event fired when the collision state between two entities changed
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn detect_collisions(mut reader: Local<EventReader<CollisionEvent>>, events: Res<Events<CollisionEvent>>) {
+///     for event in reader.iter(&events) {
+///         match event {
+///             CollisionEvent::Started(entity1, entity2) => println!("Entity {:?} and {:?} started to collide", entity1, entity2),
+///             CollisionEvent::Stopped(entity1, entity2) => println!("Entity {:?} and {:?} stopped to collide", entity1, entity2),
+///         }
+///     }
+/// }
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CollisionEvent {
+    /// The two entities started to collide
+    Started(Entity, Entity),
+
+    /// The two entities no longer collide
+    Stopped(Entity, Entity),
+}
+
+/// The geometric details of a single contact between two colliding entities.
+///
+/// Coordinates are expressed in world space.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Contact {
+    /// Point of contact, in world space.
+    pub point: Vec3,
+
+    /// Collision normal, in world space, pointing from `entity1` to `entity2`.
+    pub normal: Vec3,
+
+    /// How deep the two shapes are overlapping along `normal`.
+    ///
+    /// A positive value means the shapes are overlapping, a negative value means they are
+    /// still separated by that distance.
+    pub penetration: f32,
+}
+
+/// An event fired every physics step for each active contact pair during the narrow phase.
+///
+/// Unlike [`CollisionEvent`], which only fires on the *started*/*stopped* transitions,
+/// `CollisionData` is fired every step for as long as the two entities keep touching, and
+/// carries the [`Contact`] details needed to react to *where* and *how hard* the collision
+/// happened (e.g. spawning impact particles, directional knockback, surface-aligned decals).
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn detect_contacts(mut reader: Local<EventReader<CollisionData>>, events: Res<Events<CollisionData>>) {
+///     for event in reader.iter(&events) {
+///         println!(
+///             "Entity {:?} and {:?} are in contact at {:?}",
+///             event.entity1, event.entity2, event.contact.point
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CollisionData {
+    /// First entity involved in the contact.
+    pub entity1: Entity,
+
+    /// Second entity involved in the contact.
+    pub entity2: Entity,
+
+    /// Details of the contact, in world space, with [`Contact::normal`] pointing from
+    /// `entity1` to `entity2`.
+    pub contact: Contact,
+}
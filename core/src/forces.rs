@@ -0,0 +1,101 @@
+use bevy_math::Vec3;
+
+/// Component that accumulates a force (and torque) applied to a dynamic body every step, for as
+/// long as it stays attached.
+///
+/// Unlike [`Impulse`], which is consumed after a single step, `ExternalForce` behaves like a
+/// force generator: it keeps being applied step after step until removed or reset to zero.
+/// Useful for thrusters, wind or any other continuous push.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(commands: &mut Commands) {
+///     commands
+///         .spawn(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .with(Body::Sphere { radius: 1.0 })
+///         .with(ExternalForce {
+///             force: Vec3::unit_y() * 10.0,
+///             torque: Vec3::zero(),
+///         });
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExternalForce {
+    /// Force applied at the center of mass, in world space, every step.
+    pub force: Vec3,
+
+    /// Torque applied every step.
+    pub torque: Vec3,
+}
+
+impl Default for ExternalForce {
+    fn default() -> Self {
+        Self {
+            force: Vec3::zero(),
+            torque: Vec3::zero(),
+        }
+    }
+}
+
+/// One-shot command component that applies a linear and/or angular impulse to a dynamic body's
+/// center of mass.
+///
+/// The backend applies the impulse on the next step and then removes (or clears) the component,
+/// making it a fire-and-forget command. Useful for jumps and instantaneous pushes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn jump(commands: &mut Commands, entity: Entity) {
+///     commands.insert_one(
+///         entity,
+///         Impulse {
+///             linear: Vec3::unit_y() * 5.0,
+///             angular: Vec3::zero(),
+///         },
+///     );
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Impulse {
+    /// Linear impulse applied to the body's center of mass.
+    pub linear: Vec3,
+
+    /// Angular impulse applied to the body.
+    pub angular: Vec3,
+}
+
+/// One-shot command component that applies a linear impulse at a given point of a dynamic body,
+/// in world space, inducing both linear and angular motion.
+///
+/// Like [`Impulse`], it is consumed (cleared) by the backend once applied. Useful for
+/// explosions and off-center impacts.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn push(commands: &mut Commands, entity: Entity, explosion_center: Vec3, hit_point: Vec3) {
+///     commands.insert_one(
+///         entity,
+///         ImpulseAtPoint {
+///             impulse: (hit_point - explosion_center).normalize() * 5.0,
+///             point: hit_point,
+///         },
+///     );
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ImpulseAtPoint {
+    /// Linear impulse to apply.
+    pub impulse: Vec3,
+
+    /// Point, in world space, the impulse is applied at.
+    pub point: Vec3,
+}
@@ -0,0 +1,112 @@
+//! Components to connect two bodies together with a constraint.
+
+use bevy_ecs::Entity;
+use bevy_math::Vec3;
+
+/// Component that constrains the relative motion between this entity's body and another one.
+///
+/// The entity the joint is attached to only needs a [`Body`](crate::Body); the joint itself
+/// lives on one of the two entities and names the other one as `body2`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// # use heron_core::joints::*;
+/// fn spawn(commands: &mut Commands, anchor: Entity) {
+///     commands
+///         .spawn(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .with(Body::Sphere { radius: 1.0 })
+///         .with(Joint {
+///             body2: anchor,
+///             joint_type: JointType::Revolute {
+///                 axis: Vec3::unit_z(),
+///                 anchor1: Vec3::zero(),
+///                 anchor2: Vec3::zero(),
+///             },
+///             limits: None,
+///             stiffness: None,
+///         });
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Joint {
+    /// The other body this joint connects the current entity's body to.
+    pub body2: Entity,
+
+    /// The kind of constraint applied between the two bodies.
+    pub joint_type: JointType,
+
+    /// Optional limit on the joint's remaining degree(s) of freedom (min/max angle for
+    /// [`Revolute`](JointType::Revolute), min/max distance for [`Prismatic`](JointType::Prismatic)).
+    ///
+    /// Ignored by [`Fixed`](JointType::Fixed) and [`Spherical`](JointType::Spherical) joints.
+    pub limits: Option<JointLimits>,
+
+    /// Optional stiffness of the joint, making it behave as a spring instead of a rigid
+    /// constraint. The higher the stiffness, the more rigid the joint.
+    ///
+    /// `None` means a perfectly rigid joint.
+    pub stiffness: Option<f32>,
+}
+
+/// The kind of constraint a [`Joint`] applies between the two bodies it connects.
+///
+/// Anchor points are expressed in the local space of each body.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JointType {
+    /// Locks all relative motion between the two bodies, as if they were welded together.
+    Fixed {
+        /// Anchor point, in the local space of the first body.
+        anchor1: Vec3,
+
+        /// Anchor point, in the local space of the second body.
+        anchor2: Vec3,
+    },
+
+    /// A hinge, leaving one rotational degree of freedom around `axis`.
+    Revolute {
+        /// Axis of rotation, shared by both bodies.
+        axis: Vec3,
+
+        /// Anchor point, in the local space of the first body.
+        anchor1: Vec3,
+
+        /// Anchor point, in the local space of the second body.
+        anchor2: Vec3,
+    },
+
+    /// A slider, leaving one translational degree of freedom along `axis`.
+    Prismatic {
+        /// Axis the bodies may slide along, shared by both bodies.
+        axis: Vec3,
+
+        /// Anchor point, in the local space of the first body.
+        anchor1: Vec3,
+
+        /// Anchor point, in the local space of the second body.
+        anchor2: Vec3,
+    },
+
+    /// A ball-and-socket joint, leaving all three rotational degrees of freedom free.
+    Spherical {
+        /// Anchor point, in the local space of the first body.
+        anchor1: Vec3,
+
+        /// Anchor point, in the local space of the second body.
+        anchor2: Vec3,
+    },
+}
+
+/// Limits applied to the remaining free degree(s) of freedom of a [`Joint`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct JointLimits {
+    /// Lower bound (an angle in radians for [`Revolute`](JointType::Revolute), a distance for
+    /// [`Prismatic`](JointType::Prismatic)).
+    pub min: f32,
+
+    /// Upper bound (an angle in radians for [`Revolute`](JointType::Revolute), a distance for
+    /// [`Prismatic`](JointType::Prismatic)).
+    pub max: f32,
+}
@@ -3,13 +3,21 @@
 
 //! Core components and resources to use Heron
 
-use bevy_ecs::Entity;
 use bevy_math::Vec3;
 
+pub use collision::{CollisionData, CollisionEvent, Contact};
+pub use forces::{ExternalForce, Impulse, ImpulseAtPoint};
 pub use gravity::Gravity;
+pub use mass::{Mass, MassProperties};
+pub use modifiers::{Damping, GravityScale, LockedAxes};
 pub use velocity::{AxisAngle, Velocity};
 
+mod collision;
+mod forces;
 mod gravity;
+pub mod joints;
+mod mass;
+mod modifiers;
 pub mod utils;
 mod velocity;
 
@@ -49,6 +57,45 @@ pub enum Body {
         /// In 2d the `z` axis is ignored
         half_extends: Vec3,
     },
+
+    /// A convex hull shape, computed from a cloud of points.
+    ///
+    /// Unlike [`TriMesh`](Body::TriMesh), a convex hull can be used on dynamic bodies.
+    ///
+    /// Use [`utils::convex_hull_from_mesh`] to build one directly from a Bevy [`Mesh`](bevy_render::mesh::Mesh).
+    ConvexHull {
+        /// Point cloud the hull is computed from.
+        points: Vec<Vec3>,
+    },
+
+    /// An exact triangle mesh shape, typically used for static level geometry.
+    ///
+    /// A `TriMesh` is not convex and has no meaningful mass properties, so it should only be
+    /// used with [`BodyType::Static`].
+    ///
+    /// Use [`utils::trimesh_from_mesh`] to build one directly from a Bevy [`Mesh`](bevy_render::mesh::Mesh).
+    TriMesh {
+        /// Vertices of the mesh, in the body's local space.
+        vertices: Vec<Vec3>,
+
+        /// Triangles, as indices into `vertices`.
+        indices: Vec<[u32; 3]>,
+    },
+
+    /// A heightfield shape, for terrain.
+    Heightfield {
+        /// Height values, in row-major order, with `num_rows * num_cols` elements.
+        heights: Vec<f32>,
+
+        /// Number of rows in the heightfield grid.
+        num_rows: u32,
+
+        /// Number of columns in the heightfield grid.
+        num_cols: u32,
+
+        /// Scale applied to the heightfield, stretching it along each axis.
+        scale: Vec3,
+    },
 }
 
 /// Component that defines the *type* of rigid body.
@@ -84,6 +131,39 @@ pub enum BodyType {
     /// A sensor is useful when we are only interested in collision events.
     /// One may for example add a sensor to detect when the player reach a certain area.
     Sensor,
+
+    /// A kinematic body driven by its [`Velocity`] component.
+    ///
+    /// It is immune to forces, gravity and collisions (nothing moves it), but the engine
+    /// integrates its position from its velocity every step, and it still pushes dynamic
+    /// bodies out of the way and generates [`CollisionEvent`]s.
+    ///
+    /// Useful for moving platforms and character controllers driven by velocity.
+    KinematicVelocityBased,
+
+    /// A kinematic body driven directly by writing its `Transform`/`GlobalTransform` every frame.
+    ///
+    /// Like [`KinematicVelocityBased`](BodyType::KinematicVelocityBased), it is immune to forces,
+    /// gravity and collisions, but still pushes dynamic bodies out of the way and generates
+    /// [`CollisionEvent`]s. The engine computes an implicit velocity from the transform delta
+    /// each step, so that dynamic bodies it pushes receive correct momentum.
+    ///
+    /// Useful for moving platforms, elevators and character controllers driven by animation or
+    /// direct transform manipulation.
+    KinematicPositionBased,
+}
+
+impl BodyType {
+    /// Returns `true` if the body is one of the kinematic variants (driven by velocity or by
+    /// directly writing its transform), as opposed to [`Dynamic`](BodyType::Dynamic),
+    /// [`Static`](BodyType::Static) or [`Sensor`](BodyType::Sensor).
+    #[must_use]
+    pub fn is_kinematic(self) -> bool {
+        matches!(
+            self,
+            Self::KinematicVelocityBased | Self::KinematicPositionBased
+        )
+    }
 }
 
 impl Default for BodyType {
@@ -92,30 +172,6 @@ impl Default for BodyType {
     }
 }
 
-/// An event fired when the collision state between two entities changed
-///
-/// # Example
-///
-/// ```
-/// # use bevy::prelude::*;
-/// # use heron_core::*;
-/// fn detect_collisions(mut reader: Local<EventReader<CollisionEvent>>, events: Res<Events<CollisionEvent>>) {
-///     for event in reader.iter(&events) {
-///         match event {
-///             CollisionEvent::Started(entity1, entity2) => println!("Entity {:?} and {:?} started to collide", entity1, entity2),
-///             CollisionEvent::Stopped(entity1, entity2) => println!("Entity {:?} and {:?} stopped to collide", entity1, entity2),
-///         }   
-///     }   
-/// }
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub enum CollisionEvent {
-    /// The two entities started to collide
-    Started(Entity, Entity),
-
-    /// The two entities no longer collide
-    Stopped(Entity, Entity),
-}
-
 /// Component that defines the physics properties of the rigid body
 ///
 /// # Example
@@ -129,6 +185,8 @@ pub enum CollisionEvent {
 ///         .with(PhysicMaterial {
 ///             restitution: 0.5, // Define the restitution. Higher value means more "bouncy"
 ///             density: 2.0, // Define the density. Higher value means heavier.
+///             friction: 0.8, // Define the friction. Higher value means more friction.
+///             ..Default::default()
 ///         });
 /// }
 /// ```
@@ -147,6 +205,19 @@ pub struct PhysicMaterial {
     ///
     /// Value must be greater than 0. Except for sensor and static bodies, in which case the value is ignored.
     pub density: f32,
+
+    /// Coefficient of friction. Affects how much it resists sliding against other surfaces.
+    ///
+    /// The higher the value, the more it resists sliding.
+    ///
+    /// Typical values are between 0 (frictionless) and 1 (high friction)
+    pub friction: f32,
+
+    /// Rule used to combine the [`friction`](#structfield.friction) of this material with the friction of the other material in contact.
+    pub friction_combine_rule: CoefficientCombine,
+
+    /// Rule used to combine the [`restitution`](#structfield.restitution) of this material with the restitution of the other material in contact.
+    pub restitution_combine_rule: CoefficientCombine,
 }
 
 impl PhysicMaterial {
@@ -162,6 +233,116 @@ impl Default for PhysicMaterial {
         Self {
             restitution: Self::PERFECTLY_INELASTIC_RESTITUTION,
             density: 1.0,
+            friction: 0.5,
+            friction_combine_rule: CoefficientCombine::Average,
+            restitution_combine_rule: CoefficientCombine::Average,
+        }
+    }
+}
+
+/// Rule used to combine a coefficient (friction or restitution) defined on two different materials in contact.
+///
+/// When two bodies with different rules touch, the *stricter* rule wins, ordered as:
+/// [`Average`](CoefficientCombine::Average) < [`Min`](CoefficientCombine::Min)
+/// < [`Multiply`](CoefficientCombine::Multiply) < [`Max`](CoefficientCombine::Max)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum CoefficientCombine {
+    /// Use the average of both coefficients.
+    Average,
+
+    /// Use the smallest of both coefficients.
+    Min,
+
+    /// Multiply both coefficients together.
+    Multiply,
+
+    /// Use the largest of both coefficients.
+    Max,
+}
+
+impl Default for CoefficientCombine {
+    fn default() -> Self {
+        Self::Average
+    }
+}
+
+impl CoefficientCombine {
+    /// Resolve the effective coefficient for a contact, combining `self` and `other` using
+    /// whichever of the two rules is the stricter one.
+    #[must_use]
+    pub fn resolve(self, coefficient1: f32, other: Self, coefficient2: f32) -> f32 {
+        match self.max(other) {
+            Self::Average => (coefficient1 + coefficient2) / 2.0,
+            Self::Min => coefficient1.min(coefficient2),
+            Self::Multiply => coefficient1 * coefficient2,
+            Self::Max => coefficient1.max(coefficient2),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinematic_body_types_report_as_kinematic() {
+        assert!(BodyType::KinematicVelocityBased.is_kinematic());
+        assert!(BodyType::KinematicPositionBased.is_kinematic());
+    }
+
+    #[test]
+    fn non_kinematic_body_types_report_as_not_kinematic() {
+        assert!(!BodyType::Dynamic.is_kinematic());
+        assert!(!BodyType::Static.is_kinematic());
+        assert!(!BodyType::Sensor.is_kinematic());
+    }
+
+    #[test]
+    fn resolve_uses_the_stricter_combine_rule() {
+        assert_eq!(
+            CoefficientCombine::Average.resolve(0.2, CoefficientCombine::Max, 0.8),
+            0.8,
+        );
+        assert_eq!(
+            CoefficientCombine::Max.resolve(0.2, CoefficientCombine::Average, 0.8),
+            0.8,
+        );
+    }
+
+    #[test]
+    fn resolve_average() {
+        assert_eq!(
+            CoefficientCombine::Average.resolve(0.2, CoefficientCombine::Average, 0.8),
+            0.5,
+        );
+    }
+
+    #[test]
+    fn resolve_min() {
+        assert_eq!(
+            CoefficientCombine::Min.resolve(0.2, CoefficientCombine::Min, 0.8),
+            0.2,
+        );
+    }
+
+    #[test]
+    fn resolve_multiply() {
+        assert_eq!(
+            CoefficientCombine::Multiply.resolve(0.2, CoefficientCombine::Multiply, 0.5),
+            0.1,
+        );
+    }
+}
+
+/// Marker component that enables continuous collision detection (CCD) for a body.
+///
+/// Fast-moving bodies (bullets, fast platforms) can tunnel through thin colliders because,
+/// without CCD, collisions are only resolved discretely at each step. Adding this component
+/// makes the backend test the body's swept trajectory between the previous and current step
+/// against other colliders, respecting the time-of-impact instead of only the end position.
+///
+/// CCD is opt-in because it is significantly more expensive than discrete collision detection.
+/// It is only meaningful on dynamic and kinematic bodies; it has no effect on
+/// [`Static`](BodyType::Static) or [`Sensor`](BodyType::Sensor) bodies.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct ContinuousCollisionDetection;
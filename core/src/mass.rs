@@ -0,0 +1,136 @@
+use bevy_math::Vec3;
+
+/// Component that overrides the mass properties of a dynamic body, decoupling them from the
+/// shape volume and [`PhysicMaterial::density`](crate::PhysicMaterial::density).
+///
+/// When present on a dynamic body, it overrides the mass, center of mass and moment of inertia
+/// that would otherwise be computed from the shape and the material density. It is ignored on
+/// static and sensor bodies.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(commands: &mut Commands) {
+///     commands
+///         .spawn(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .with(Body::Sphere { radius: 1.0 })
+///         .with(Mass::Real(10.0)); // A heavy, light-looking ball
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Mass {
+    /// An infinite mass, making the body immovable, as if it were
+    /// [`Static`](crate::BodyType::Static), while it keeps participating as a dynamic body for
+    /// joints.
+    Infinite,
+
+    /// An explicit mass value (in mass units), overriding the one derived from the shape volume
+    /// and the material density.
+    Real(f32),
+}
+
+impl Mass {
+    /// Turn this `Mass` into a [`MassProperties`], with no center of mass or moment of inertia
+    /// override (both default to the shape's own values).
+    #[must_use]
+    pub fn with_defaults(self) -> MassProperties {
+        MassProperties {
+            mass: self,
+            local_center_of_mass: None,
+            moment_of_inertia: None,
+        }
+    }
+
+    /// Turn this `Mass` into a [`MassProperties`] with the body's local center of mass
+    /// overridden.
+    #[must_use]
+    pub fn with_local_center_of_mass(self, local_center_of_mass: Vec3) -> MassProperties {
+        self.with_defaults()
+            .with_local_center_of_mass(local_center_of_mass)
+    }
+
+    /// Turn this `Mass` into a [`MassProperties`] with the body's principal moment of inertia
+    /// overridden.
+    #[must_use]
+    pub fn with_moment_of_inertia(self, moment_of_inertia: Vec3) -> MassProperties {
+        self.with_defaults()
+            .with_moment_of_inertia(moment_of_inertia)
+    }
+}
+
+/// Full mass properties override for a dynamic body, built from a [`Mass`] via
+/// [`Mass::with_local_center_of_mass`], [`Mass::with_moment_of_inertia`] or [`Mass::with_defaults`],
+/// and further customized through its own builder methods so both overrides can be chained
+/// together.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MassProperties {
+    /// The overridden mass.
+    pub mass: Mass,
+
+    /// Override of the local center of mass, in the body's local space. `None` means the
+    /// shape's own centroid is used.
+    pub local_center_of_mass: Option<Vec3>,
+
+    /// Override of the principal moment of inertia, if any. `None` means the shape's own
+    /// moment of inertia is used.
+    pub moment_of_inertia: Option<Vec3>,
+}
+
+impl MassProperties {
+    /// Return a copy of these `MassProperties` with the local center of mass override set.
+    #[must_use]
+    pub fn with_local_center_of_mass(mut self, local_center_of_mass: Vec3) -> Self {
+        self.local_center_of_mass = Some(local_center_of_mass);
+        self
+    }
+
+    /// Return a copy of these `MassProperties` with the moment of inertia override set.
+    #[must_use]
+    pub fn with_moment_of_inertia(mut self, moment_of_inertia: Vec3) -> Self {
+        self.moment_of_inertia = Some(moment_of_inertia);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_defaults_overrides_neither_center_of_mass_nor_inertia() {
+        let properties = Mass::Real(10.0).with_defaults();
+
+        assert_eq!(properties.mass, Mass::Real(10.0));
+        assert_eq!(properties.local_center_of_mass, None);
+        assert_eq!(properties.moment_of_inertia, None);
+    }
+
+    #[test]
+    fn with_local_center_of_mass_only_overrides_center_of_mass() {
+        let properties = Mass::Real(10.0).with_local_center_of_mass(Vec3::unit_x());
+
+        assert_eq!(properties.local_center_of_mass, Some(Vec3::unit_x()));
+        assert_eq!(properties.moment_of_inertia, None);
+    }
+
+    #[test]
+    fn with_moment_of_inertia_only_overrides_inertia() {
+        let properties = Mass::Real(10.0).with_moment_of_inertia(Vec3::unit_y());
+
+        assert_eq!(properties.local_center_of_mass, None);
+        assert_eq!(properties.moment_of_inertia, Some(Vec3::unit_y()));
+    }
+
+    #[test]
+    fn builder_methods_chain_to_override_both_fields() {
+        let properties = Mass::Real(10.0)
+            .with_local_center_of_mass(Vec3::unit_x())
+            .with_moment_of_inertia(Vec3::unit_y());
+
+        assert_eq!(properties.mass, Mass::Real(10.0));
+        assert_eq!(properties.local_center_of_mass, Some(Vec3::unit_x()));
+        assert_eq!(properties.moment_of_inertia, Some(Vec3::unit_y()));
+    }
+}
@@ -0,0 +1,103 @@
+use bitflags::bitflags;
+
+/// Component that makes the body's velocity decay over time, independently of collisions.
+///
+/// Each step, the linear and angular velocities are scaled down by `1 / (1 + dt * damping)`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use heron_core::*;
+/// fn spawn(commands: &mut Commands) {
+///     commands
+///         .spawn(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+///         .with(Body::Sphere { radius: 1.0 })
+///         .with(Damping::from_linear(0.5).with_angular(0.8));
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Damping {
+    /// Decay rate applied to the linear velocity.
+    pub linear: f32,
+
+    /// Decay rate applied to the angular velocity.
+    pub angular: f32,
+}
+
+impl Damping {
+    /// Create a `Damping` with the given linear decay rate and no angular damping.
+    #[must_use]
+    pub fn from_linear(linear: f32) -> Self {
+        Self {
+            linear,
+            angular: 0.0,
+        }
+    }
+
+    /// Return a copy of this `Damping` with the angular decay rate set.
+    #[must_use]
+    pub fn with_angular(mut self, angular: f32) -> Self {
+        self.angular = angular;
+        self
+    }
+}
+
+impl Default for Damping {
+    fn default() -> Self {
+        Self {
+            linear: 0.0,
+            angular: 0.0,
+        }
+    }
+}
+
+/// Component that multiplies the effect of the global [`Gravity`](crate::Gravity) on this body.
+///
+/// A value of `1.0` (the default, when the component is absent) means the body is affected by
+/// gravity normally, `0.0` makes it float, and values above `1.0` make it fall faster than other
+/// bodies in the same world (e.g. bullets).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GravityScale(pub f32);
+
+impl Default for GravityScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+bitflags! {
+    /// Component that freezes the chosen translation and/or rotation axes of a body.
+    ///
+    /// Useful to keep a 3d body confined to a 2d plane, or to prevent an upright character from
+    /// toppling over.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use heron_core::*;
+    /// fn spawn(commands: &mut Commands) {
+    ///     commands
+    ///         .spawn(todo!("Spawn your sprite/mesh, incl. at least a GlobalTransform"))
+    ///         .with(Body::Sphere { radius: 1.0 })
+    ///         // Keep the body on the XY plane and prevent it from toppling over.
+    ///         .with(LockedAxes::TRANSLATION_Z | LockedAxes::ROTATION_X | LockedAxes::ROTATION_Y);
+    /// }
+    /// ```
+    #[derive(Default)]
+    pub struct LockedAxes: u8 {
+        /// Freeze translation along the x axis.
+        const TRANSLATION_X = 1 << 0;
+        /// Freeze translation along the y axis.
+        const TRANSLATION_Y = 1 << 1;
+        /// Freeze translation along the z axis.
+        const TRANSLATION_Z = 1 << 2;
+        /// Freeze rotation around the x axis.
+        const ROTATION_X = 1 << 3;
+        /// Freeze rotation around the y axis.
+        const ROTATION_Y = 1 << 4;
+        /// Freeze rotation around the z axis.
+        const ROTATION_Z = 1 << 5;
+    }
+}
@@ -0,0 +1,130 @@
+//! Helpers to build [`Body`](crate::Body) shapes from other data sources.
+
+use bevy_math::Vec3;
+use bevy_render::mesh::{Indices, Mesh, VertexAttributeValues};
+
+use crate::Body;
+
+/// Build a [`Body::ConvexHull`] from the position attribute of a Bevy [`Mesh`].
+///
+/// This lets a rendered mesh be dropped straight into a collider, letting the backend compute
+/// its convex hull.
+///
+/// # Panics
+///
+/// Panics if `mesh` has no [`Mesh::ATTRIBUTE_POSITION`] attribute, or if that attribute isn't
+/// stored as `Float3` values.
+#[must_use]
+pub fn convex_hull_from_mesh(mesh: &Mesh) -> Body {
+    Body::ConvexHull {
+        points: read_positions(mesh),
+    }
+}
+
+/// Build a [`Body::TriMesh`] from the position and index buffers of a Bevy [`Mesh`].
+///
+/// This lets a rendered mesh be used as-is for exact, static collision geometry.
+///
+/// # Panics
+///
+/// Panics if `mesh` has no [`Mesh::ATTRIBUTE_POSITION`] attribute or no index buffer, if the
+/// position attribute isn't stored as `Float3` values, or if the index buffer's length isn't a
+/// multiple of 3.
+#[must_use]
+pub fn trimesh_from_mesh(mesh: &Mesh) -> Body {
+    let vertices = read_positions(mesh);
+
+    let indices: Vec<u32> = match mesh.indices() {
+        Some(Indices::U16(indices)) => indices.iter().map(|&index| u32::from(index)).collect(),
+        Some(Indices::U32(indices)) => indices.clone(),
+        None => panic!("the mesh has no index buffer"),
+    };
+
+    assert_eq!(
+        indices.len() % 3,
+        0,
+        "the mesh's index buffer length must be a multiple of 3"
+    );
+
+    let indices = indices
+        .chunks_exact(3)
+        .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+        .collect();
+
+    Body::TriMesh { vertices, indices }
+}
+
+fn read_positions(mesh: &Mesh) -> Vec<Vec3> {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float3(positions)) => positions
+            .iter()
+            .map(|&[x, y, z]| Vec3::new(x, y, z))
+            .collect(),
+        _ => panic!("the mesh has no `Float3` position attribute"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_render::pipeline::PrimitiveTopology;
+
+    use super::*;
+
+    fn triangle_mesh() -> Mesh {
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.set_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            VertexAttributeValues::Float3(vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+            ]),
+        );
+        mesh
+    }
+
+    #[test]
+    fn trimesh_from_mesh_builds_the_expected_body() {
+        let mut mesh = triangle_mesh();
+        mesh.set_indices(Some(Indices::U32(vec![0, 1, 2])));
+
+        match trimesh_from_mesh(&mesh) {
+            Body::TriMesh { vertices, indices } => {
+                assert_eq!(vertices.len(), 3);
+                assert_eq!(indices, vec![[0, 1, 2]]);
+            }
+            body => panic!("expected a Body::TriMesh, got {:?}", body),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple of 3")]
+    fn trimesh_from_mesh_panics_on_a_malformed_index_buffer() {
+        let mut mesh = triangle_mesh();
+        mesh.set_indices(Some(Indices::U32(vec![0, 1])));
+
+        trimesh_from_mesh(&mesh);
+    }
+
+    #[test]
+    #[should_panic(expected = "no index buffer")]
+    fn trimesh_from_mesh_panics_without_an_index_buffer() {
+        trimesh_from_mesh(&triangle_mesh());
+    }
+
+    #[test]
+    #[should_panic(expected = "position attribute")]
+    fn convex_hull_from_mesh_panics_without_a_position_attribute() {
+        let mesh = Mesh::new(PrimitiveTopology::TriangleList);
+
+        convex_hull_from_mesh(&mesh);
+    }
+
+    #[test]
+    fn convex_hull_from_mesh_builds_the_expected_body() {
+        match convex_hull_from_mesh(&triangle_mesh()) {
+            Body::ConvexHull { points } => assert_eq!(points.len(), 3),
+            body => panic!("expected a Body::ConvexHull, got {:?}", body),
+        }
+    }
+}